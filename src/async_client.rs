@@ -0,0 +1,438 @@
+use crate::config::{self, RedisConfig};
+use crate::pipeline::RedisPipeline;
+use crate::pool::{pool_err_to_napi_err, RedisConnectionManager};
+use crate::pubsub::{self, AsyncSubscriptionHandle, PubSubMessage};
+use crate::scan::{self, ScanResult};
+use crate::{redis_err_to_napi_err, redis_to_napi, redis_to_napi_optional, ScoredMember};
+use bb8::{Pool, PooledConnection};
+use napi::bindgen_prelude::{Either, Either3, Null};
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction};
+use napi_derive::napi;
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, RedisResult};
+use std::collections::HashMap;
+
+const DEFAULT_POOL_SIZE: u32 = 10;
+
+// Non-blocking counterpart to `RedisClient`. Every method hands back a JS
+// Promise instead of parking Bun's event loop for the round-trip. Commands
+// are run against a pooled `ConnectionManager` so concurrent awaited calls
+// from Bun check out their own connection instead of serializing over one
+// socket; the manager also reconnects on its own, so there is no
+// `reconnect()` to call here. `client` is kept alongside the pool to open
+// the dedicated connections pub/sub needs outside of it.
+#[napi]
+pub struct AsyncRedisClient {
+  client: redis::Client,
+  pool: Pool<RedisConnectionManager>,
+}
+
+impl AsyncRedisClient {
+  async fn connection(&self) -> napi::Result<PooledConnection<'_, RedisConnectionManager>> {
+    self.pool.get().await.map_err(pool_err_to_napi_err)
+  }
+}
+
+#[napi]
+impl AsyncRedisClient {
+  #[napi(factory)]
+  pub async fn connect(url: String, pool_size: Option<u32>) -> napi::Result<Self> {
+    let client = match redis::Client::open(url) {
+      Ok(client) => client,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    let size = pool_size.unwrap_or(DEFAULT_POOL_SIZE);
+    let pool = match Pool::builder()
+      .max_size(size)
+      .min_idle(Some(size.min(1)))
+      .build(RedisConnectionManager::new(client.clone()))
+      .await
+    {
+      Ok(pool) => pool,
+      Err(e) => return Err(pool_err_to_napi_err(e)),
+    };
+
+    // `min_idle` only tells bb8's background replenishment task to maintain
+    // idle connections eventually; check one out and back in here so
+    // `connection_open()`/`idle_connections()` reflect a real, already
+    // warmed connection rather than a best-effort background fill.
+    drop(pool.get().await.map_err(pool_err_to_napi_err)?);
+
+    Ok(Self { client, pool })
+  }
+
+  /// Builds a pooled connection from structured options (TLS, Unix sockets,
+  /// auth, DB selection, timeouts) instead of a bare URL string, mirroring
+  /// `RedisClient::with_config`.
+  #[napi(factory)]
+  pub async fn connect_with_config(config: RedisConfig, pool_size: Option<u32>) -> napi::Result<Self> {
+    let connection_info = config::build_connection_info(&config);
+
+    let client = match redis::Client::open(connection_info) {
+      Ok(client) => client,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    let manager = RedisConnectionManager::with_timeouts(
+      client.clone(),
+      config.connect_timeout_ms,
+      config.response_timeout_ms,
+    );
+
+    let size = pool_size.unwrap_or(DEFAULT_POOL_SIZE);
+    let pool = match Pool::builder()
+      .max_size(size)
+      .min_idle(Some(size.min(1)))
+      .build(manager)
+      .await
+    {
+      Ok(pool) => pool,
+      Err(e) => return Err(pool_err_to_napi_err(e)),
+    };
+
+    drop(pool.get().await.map_err(pool_err_to_napi_err)?);
+
+    Ok(Self { client, pool })
+  }
+
+  #[napi]
+  pub fn active_connections(&self) -> u32 {
+    let state = self.pool.state();
+    state.connections - state.idle_connections
+  }
+
+  #[napi]
+  pub fn idle_connections(&self) -> u32 {
+    self.pool.state().idle_connections
+  }
+
+  #[napi]
+  pub async fn connection_open(&self) -> bool {
+    self.pool.state().connections > 0
+  }
+
+  // PUB/SUB
+
+  #[napi]
+  pub async fn subscribe(
+    &self,
+    channels: Vec<String>,
+    on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+  ) -> napi::Result<AsyncSubscriptionHandle> {
+    pubsub::subscribe_async(&self.client, channels, on_message).await
+  }
+
+  #[napi]
+  pub async fn psubscribe(
+    &self,
+    patterns: Vec<String>,
+    on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+  ) -> napi::Result<AsyncSubscriptionHandle> {
+    pubsub::psubscribe_async(&self.client, patterns, on_message).await
+  }
+
+  // PIPELINE / TRANSACTIONS
+
+  #[napi]
+  pub fn pipeline(&self) -> RedisPipeline {
+    RedisPipeline::new(self.client.clone())
+  }
+
+  // HIGH-LEVEL BINDINGS
+
+  #[napi]
+  pub async fn get(
+    &self,
+    key: String,
+  ) -> napi::Result<Either3<String, HashMap<String, String>, Null>> {
+    let mut conn = self.connection().await?;
+
+    let field_type: String = match redis::cmd("TYPE").arg(&key).query_async(&mut *conn).await {
+      Ok(val) => val,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    match field_type.as_str() {
+      "none" => Ok(Either3::C(Null)),
+      "string" => match conn.get(&key).await {
+        Ok(val) => Ok(Either3::A(val)),
+        Err(e) => Err(redis_err_to_napi_err(&e)),
+      },
+      "hash" => match conn.hgetall(&key).await {
+        Ok(val) => Ok(Either3::B(val)),
+        Err(e) => Err(redis_err_to_napi_err(&e)),
+      },
+      _ => Err(napi::Error::new(
+        napi::Status::Unknown,
+        "field type unknown".to_string(),
+      )),
+    }
+  }
+
+  // COMMAND BINDINGS
+
+  // Unsafe
+
+  async fn execute<T: redis::FromRedisValue>(&self, args: &Vec<String>) -> RedisResult<T> {
+    let mut conn = self.pool.get().await.map_err(pool_err_to_napi_err_as_redis)?;
+    redis::Cmd::new().arg(args).query_async(&mut *conn).await
+  }
+
+  #[napi]
+  pub async fn expect_string(&self, args: Vec<String>) -> napi::Result<String> {
+    redis_to_napi(self.execute(&args).await)
+  }
+
+  #[napi]
+  pub async fn expect_array(&self, args: Vec<String>) -> napi::Result<Vec<String>> {
+    redis_to_napi(self.execute(&args).await)
+  }
+
+  #[napi]
+  pub async fn expect_integer(&self, args: Vec<String>) -> napi::Result<u32> {
+    redis_to_napi(self.execute(&args).await)
+  }
+
+  #[napi]
+  pub async fn expect_nil(&self, args: Vec<String>) -> napi::Result<()> {
+    redis_to_napi(self.execute(&args).await)
+  }
+
+  // Getters/setters
+
+  #[napi(js_name = "cmdGET")]
+  pub async fn cmd_get(&self, key: String) -> napi::Result<Option<String>> {
+    let mut conn = self.connection().await?;
+    redis_to_napi_optional(conn.get(key).await)
+  }
+
+  #[napi(js_name = "cmdSET")]
+  pub async fn cmd_set(&self, key: String, value: String) -> napi::Result<()> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.set(key, value).await)
+  }
+
+  #[napi(js_name = "cmdLPUSH")]
+  pub async fn cmd_lpush(&self, key: String, value: Vec<String>) -> napi::Result<()> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.lpush(key, value).await)
+  }
+
+  #[napi(js_name = "cmdLPOP")]
+  pub async fn cmd_lpop(&self, key: String, count: u32) -> napi::Result<Option<Vec<String>>> {
+    let mut conn = self.connection().await?;
+    redis_to_napi_optional(
+      conn
+        .lpop(key, std::num::NonZeroUsize::new(count as usize))
+        .await,
+    )
+  }
+
+  #[napi(js_name = "cmdHSET")]
+  pub async fn cmd_hset(&self, key: String, field: String, value: String) -> napi::Result<()> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.hset(key, field, value).await)
+  }
+
+  #[napi(js_name = "cmdHGET")]
+  pub async fn cmd_hget(&self, key: String, field: String) -> napi::Result<Option<String>> {
+    let mut conn = self.connection().await?;
+    redis_to_napi_optional(conn.hget(key, field).await)
+  }
+
+  #[napi(js_name = "cmdHGETALL")]
+  pub async fn cmd_hgetall(
+    &self,
+    key: String,
+  ) -> napi::Result<Option<HashMap<String, String>>> {
+    let mut conn = self.connection().await?;
+    redis_to_napi_optional(conn.hgetall(key).await)
+  }
+
+  // Sorted sets
+
+  #[napi(js_name = "cmdZADD")]
+  pub async fn cmd_zadd(&self, key: String, member: String, score: f64) -> napi::Result<u32> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.zadd(key, member, score).await)
+  }
+
+  #[napi(js_name = "cmdZRANGE")]
+  pub async fn cmd_zrange(
+    &self,
+    key: String,
+    start: i32,
+    stop: i32,
+  ) -> napi::Result<Vec<ScoredMember>> {
+    let mut conn = self.connection().await?;
+    let members: Vec<(String, f64)> =
+      redis_to_napi(conn.zrange_withscores(key, start as isize, stop as isize).await)?;
+    Ok(
+      members
+        .into_iter()
+        .map(|(member, score)| ScoredMember { member, score })
+        .collect(),
+    )
+  }
+
+  #[napi(js_name = "cmdZRANGEBYSCORE")]
+  pub async fn cmd_zrangebyscore(
+    &self,
+    key: String,
+    min: f64,
+    max: f64,
+  ) -> napi::Result<Vec<String>> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.zrangebyscore(key, min, max).await)
+  }
+
+  // Sets
+
+  #[napi(js_name = "cmdSADD")]
+  pub async fn cmd_sadd(
+    &self,
+    key: String,
+    member: Either<String, Vec<String>>,
+  ) -> napi::Result<u32> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(match member {
+      Either::A(val) => conn.sadd(key, val).await,
+      Either::B(val) => conn.sadd(key, val).await,
+    })
+  }
+
+  #[napi(js_name = "cmdSMEMBERS")]
+  pub async fn cmd_smembers(&self, key: String) -> napi::Result<Vec<String>> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.smembers(key).await)
+  }
+
+  #[napi(js_name = "cmdSISMEMBER")]
+  pub async fn cmd_sismember(&self, key: String, member: String) -> napi::Result<bool> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.sismember(key, member).await)
+  }
+
+  // Counters
+
+  #[napi(js_name = "cmdINCRBY")]
+  pub async fn cmd_incrby(&self, key: String, amount: i64) -> napi::Result<i64> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.incr(key, amount).await)
+  }
+
+  #[napi(js_name = "cmdDECRBY")]
+  pub async fn cmd_decrby(&self, key: String, amount: i64) -> napi::Result<i64> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.decr(key, amount).await)
+  }
+
+  // Utilities
+
+  #[napi(js_name = "cmdEXPIRE")]
+  pub async fn cmd_expire(&self, key: String, seconds: u32) -> napi::Result<u32> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.expire(key, seconds as i64).await)
+  }
+
+  #[napi(js_name = "cmdDEL")]
+  pub async fn cmd_del(&self, key: Either<String, Vec<String>>) -> napi::Result<u32> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(match key {
+      Either::A(val) => conn.del(val).await,
+      Either::B(val) => conn.del(val).await,
+    })
+  }
+
+  #[napi(js_name = "cmdKEYS")]
+  pub async fn cmd_keys(&self, pattern: String) -> napi::Result<Vec<String>> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(conn.keys(pattern).await)
+  }
+
+  #[napi(js_name = "cmdTYPE")]
+  pub async fn cmd_type(&self, key: String) -> napi::Result<String> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(redis::cmd("TYPE").arg(key).query_async(&mut *conn).await)
+  }
+
+  // SCAN family: cursor-based replacements for the blocking, production-unsafe
+  // cmdKEYS above, same cursor protocol as `RedisClient`.
+
+  #[napi(js_name = "cmdSCAN")]
+  pub async fn cmd_scan(
+    &self,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(scan::scan_step_async(&mut conn, "SCAN", None, &cursor, pattern.as_deref(), count).await)
+  }
+
+  #[napi(js_name = "cmdHSCAN")]
+  pub async fn cmd_hscan(
+    &self,
+    key: String,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(
+      scan::scan_step_async(&mut conn, "HSCAN", Some(&key), &cursor, pattern.as_deref(), count).await,
+    )
+  }
+
+  #[napi(js_name = "cmdSSCAN")]
+  pub async fn cmd_sscan(
+    &self,
+    key: String,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(
+      scan::scan_step_async(&mut conn, "SSCAN", Some(&key), &cursor, pattern.as_deref(), count).await,
+    )
+  }
+
+  #[napi(js_name = "cmdZSCAN")]
+  pub async fn cmd_zscan(
+    &self,
+    key: String,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(
+      scan::scan_step_async(&mut conn, "ZSCAN", Some(&key), &cursor, pattern.as_deref(), count).await,
+    )
+  }
+
+  /// Drives `cmdSCAN` to completion, streaming each non-empty batch of keys
+  /// to `on_batch` until the cursor returns to `"0"`.
+  #[napi]
+  pub async fn scan_all(
+    &self,
+    pattern: Option<String>,
+    count: Option<u32>,
+    on_batch: ThreadsafeFunction<Vec<String>, ErrorStrategy::CalleeHandled>,
+  ) -> napi::Result<()> {
+    let mut conn = self.connection().await?;
+    redis_to_napi(scan::scan_all_async(&mut conn, "SCAN", None, pattern, count, on_batch).await)
+  }
+}
+
+fn pool_err_to_napi_err_as_redis(err: bb8::RunError<redis::RedisError>) -> redis::RedisError {
+  match err {
+    bb8::RunError::User(e) => e,
+    bb8::RunError::TimedOut => redis::RedisError::from(std::io::Error::new(
+      std::io::ErrorKind::TimedOut,
+      "timed out waiting for a pooled connection",
+    )),
+  }
+}
@@ -0,0 +1,67 @@
+use crate::redis_err_to_napi_err;
+use napi_derive::napi;
+use redis::{ConnectionAddr, ConnectionInfo, RedisConnectionInfo};
+use std::time::Duration;
+
+/// Structured connection options, as an alternative to hand-crafting a URL.
+/// Exactly one of `host`/`port` or `unix_socket` should be set; if both are
+/// given, the Unix socket takes priority. `tls` only applies to TCP.
+#[napi(object)]
+pub struct RedisConfig {
+  pub host: Option<String>,
+  pub port: Option<u16>,
+  pub unix_socket: Option<String>,
+  pub username: Option<String>,
+  pub password: Option<String>,
+  pub db: Option<i64>,
+  pub tls: Option<bool>,
+  pub connect_timeout_ms: Option<u32>,
+  pub response_timeout_ms: Option<u32>,
+}
+
+pub(crate) fn build_connection_info(config: &RedisConfig) -> ConnectionInfo {
+  let addr = if let Some(path) = &config.unix_socket {
+    ConnectionAddr::Unix(path.into())
+  } else {
+    let host = config.host.clone().unwrap_or_else(|| "127.0.0.1".to_string());
+    let port = config.port.unwrap_or(6379);
+    if config.tls.unwrap_or(false) {
+      ConnectionAddr::TcpTls {
+        host,
+        port,
+        insecure: false,
+        tls_params: None,
+      }
+    } else {
+      ConnectionAddr::Tcp(host, port)
+    }
+  };
+
+  ConnectionInfo {
+    addr,
+    redis: RedisConnectionInfo {
+      db: config.db.unwrap_or(0),
+      username: config.username.clone(),
+      password: config.password.clone(),
+    },
+  }
+}
+
+/// Applies `response_timeout_ms` as the read and write timeout of an
+/// already-open connection, bounding how long a blocking `execute` can hang.
+/// `connect_timeout_ms` is not applied here; it only bounds opening the
+/// connection.
+pub(crate) fn apply_timeouts(
+  connection: &mut redis::Connection,
+  config: &RedisConfig,
+) -> napi::Result<()> {
+  if let Some(ms) = config.response_timeout_ms {
+    connection
+      .set_read_timeout(Some(Duration::from_millis(ms as u64)))
+      .map_err(|e| redis_err_to_napi_err(&e))?;
+    connection
+      .set_write_timeout(Some(Duration::from_millis(ms as u64)))
+      .map_err(|e| redis_err_to_napi_err(&e))?;
+  }
+  Ok(())
+}
@@ -7,6 +7,27 @@ use redis::{Commands, ConnectionLike, RedisError, RedisResult};
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 
+mod async_client;
+mod config;
+mod pipeline;
+mod pool;
+mod pubsub;
+mod scan;
+
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction};
+
+pub use async_client::AsyncRedisClient;
+pub use config::RedisConfig;
+pub use pipeline::RedisPipeline;
+pub use pubsub::{AsyncSubscriptionHandle, PubSubMessage, SubscriptionHandle};
+pub use scan::ScanResult;
+
+#[napi(object)]
+pub struct ScoredMember {
+  pub member: String,
+  pub score: f64,
+}
+
 #[napi]
 pub struct RedisClient {
   client: redis::Client,
@@ -30,6 +51,28 @@ impl RedisClient {
     Ok(Self { client, connection })
   }
 
+  /// Builds a connection from structured options (TLS, Unix sockets, auth,
+  /// DB selection, timeouts) instead of a hand-escaped URL string.
+  #[napi(factory)]
+  pub fn with_config(config: RedisConfig) -> napi::Result<Self> {
+    let connection_info = config::build_connection_info(&config);
+
+    let client = match redis::Client::open(connection_info) {
+      Ok(client) => client,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    let mut connection = match config.connect_timeout_ms {
+      Some(ms) => client.get_connection_with_timeout(std::time::Duration::from_millis(ms as u64)),
+      None => client.get_connection(),
+    }
+    .map_err(|e| redis_err_to_napi_err(&e))?;
+
+    config::apply_timeouts(&mut connection, &config)?;
+
+    Ok(Self { client, connection })
+  }
+
   #[napi]
   pub fn reconnect(&mut self) -> napi::Result<()> {
     match self.client.get_connection() {
@@ -46,6 +89,33 @@ impl RedisClient {
     self.connection.is_open()
   }
 
+  // PUB/SUB
+
+  #[napi]
+  pub fn subscribe(
+    &self,
+    channels: Vec<String>,
+    on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+  ) -> napi::Result<SubscriptionHandle> {
+    pubsub::subscribe(&self.client, channels, on_message)
+  }
+
+  #[napi]
+  pub fn psubscribe(
+    &self,
+    patterns: Vec<String>,
+    on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+  ) -> napi::Result<SubscriptionHandle> {
+    pubsub::psubscribe(&self.client, patterns, on_message)
+  }
+
+  // PIPELINE / TRANSACTIONS
+
+  #[napi]
+  pub fn pipeline(&self) -> RedisPipeline {
+    RedisPipeline::new(self.client.clone())
+  }
+
   // HIGH-LEVEL BINDINGS
 
   #[napi]
@@ -140,6 +210,72 @@ impl RedisClient {
     redis_to_napi_optional(self.connection.hgetall(key))
   }
 
+  // Sorted sets
+
+  #[napi(js_name = "cmdZADD")]
+  pub fn cmd_zadd(&mut self, key: String, member: String, score: f64) -> napi::Result<u32> {
+    redis_to_napi(self.connection.zadd(key, member, score))
+  }
+
+  #[napi(js_name = "cmdZRANGE")]
+  pub fn cmd_zrange(
+    &mut self,
+    key: String,
+    start: i32,
+    stop: i32,
+  ) -> napi::Result<Vec<ScoredMember>> {
+    let members: Vec<(String, f64)> =
+      redis_to_napi(self.connection.zrange_withscores(key, start as isize, stop as isize))?;
+    Ok(
+      members
+        .into_iter()
+        .map(|(member, score)| ScoredMember { member, score })
+        .collect(),
+    )
+  }
+
+  #[napi(js_name = "cmdZRANGEBYSCORE")]
+  pub fn cmd_zrangebyscore(
+    &mut self,
+    key: String,
+    min: f64,
+    max: f64,
+  ) -> napi::Result<Vec<String>> {
+    redis_to_napi(self.connection.zrangebyscore(key, min, max))
+  }
+
+  // Sets
+
+  #[napi(js_name = "cmdSADD")]
+  pub fn cmd_sadd(&mut self, key: String, member: Either<String, Vec<String>>) -> napi::Result<u32> {
+    redis_to_napi(match member {
+      Either::A(val) => self.connection.sadd(key, val),
+      Either::B(val) => self.connection.sadd(key, val),
+    })
+  }
+
+  #[napi(js_name = "cmdSMEMBERS")]
+  pub fn cmd_smembers(&mut self, key: String) -> napi::Result<Vec<String>> {
+    redis_to_napi(self.connection.smembers(key))
+  }
+
+  #[napi(js_name = "cmdSISMEMBER")]
+  pub fn cmd_sismember(&mut self, key: String, member: String) -> napi::Result<bool> {
+    redis_to_napi(self.connection.sismember(key, member))
+  }
+
+  // Counters
+
+  #[napi(js_name = "cmdINCRBY")]
+  pub fn cmd_incrby(&mut self, key: String, amount: i64) -> napi::Result<i64> {
+    redis_to_napi(self.connection.incr(key, amount))
+  }
+
+  #[napi(js_name = "cmdDECRBY")]
+  pub fn cmd_decrby(&mut self, key: String, amount: i64) -> napi::Result<i64> {
+    redis_to_napi(self.connection.decr(key, amount))
+  }
+
   // Utilities
 
   #[napi(js_name = "cmdEXPIRE")]
@@ -160,20 +296,113 @@ impl RedisClient {
     redis_to_napi(self.connection.keys(pattern))
   }
 
+  // SCAN family: cursor-based replacements for the blocking, production-unsafe
+  // KEYS/HGETALL/SMEMBERS/ZRANGE-style full scans above.
+
+  #[napi(js_name = "cmdSCAN")]
+  pub fn cmd_scan(
+    &mut self,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    redis_to_napi(scan::scan_step(
+      &mut self.connection,
+      "SCAN",
+      None,
+      &cursor,
+      pattern.as_deref(),
+      count,
+    ))
+  }
+
+  #[napi(js_name = "cmdHSCAN")]
+  pub fn cmd_hscan(
+    &mut self,
+    key: String,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    redis_to_napi(scan::scan_step(
+      &mut self.connection,
+      "HSCAN",
+      Some(&key),
+      &cursor,
+      pattern.as_deref(),
+      count,
+    ))
+  }
+
+  #[napi(js_name = "cmdSSCAN")]
+  pub fn cmd_sscan(
+    &mut self,
+    key: String,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    redis_to_napi(scan::scan_step(
+      &mut self.connection,
+      "SSCAN",
+      Some(&key),
+      &cursor,
+      pattern.as_deref(),
+      count,
+    ))
+  }
+
+  #[napi(js_name = "cmdZSCAN")]
+  pub fn cmd_zscan(
+    &mut self,
+    key: String,
+    cursor: String,
+    pattern: Option<String>,
+    count: Option<u32>,
+  ) -> napi::Result<ScanResult> {
+    redis_to_napi(scan::scan_step(
+      &mut self.connection,
+      "ZSCAN",
+      Some(&key),
+      &cursor,
+      pattern.as_deref(),
+      count,
+    ))
+  }
+
+  /// Drives `cmdSCAN` to completion, streaming each non-empty batch of keys
+  /// to `on_batch` until the cursor returns to `"0"`.
+  #[napi]
+  pub fn scan_all(
+    &mut self,
+    pattern: Option<String>,
+    count: Option<u32>,
+    on_batch: ThreadsafeFunction<Vec<String>, ErrorStrategy::CalleeHandled>,
+  ) -> napi::Result<()> {
+    redis_to_napi(scan::scan_all(
+      &mut self.connection,
+      "SCAN",
+      None,
+      pattern,
+      count,
+      on_batch,
+    ))
+  }
+
   #[napi(js_name = "cmdTYPE")]
   pub fn cmd_type(&mut self, key: String) -> napi::Result<String> {
     redis_to_napi(redis::cmd("TYPE").arg(key).query(&mut self.connection))
   }
 }
 
-fn redis_to_napi<T: redis::FromRedisValue>(result: RedisResult<T>) -> napi::Result<T> {
+pub(crate) fn redis_to_napi<T: redis::FromRedisValue>(result: RedisResult<T>) -> napi::Result<T> {
   match result {
     Ok(val) => Ok(val),
     Err(e) => Err(redis_err_to_napi_err(&e)),
   }
 }
 
-fn redis_to_napi_optional<T: redis::FromRedisValue>(
+pub(crate) fn redis_to_napi_optional<T: redis::FromRedisValue>(
   result: RedisResult<T>,
 ) -> napi::Result<Option<T>> {
   match result {
@@ -185,7 +414,7 @@ fn redis_to_napi_optional<T: redis::FromRedisValue>(
   }
 }
 
-fn redis_err_to_napi_err(err: &RedisError) -> napi::Error {
+pub(crate) fn redis_err_to_napi_err(err: &RedisError) -> napi::Error {
   napi::Error::new(
     napi::Status::Cancelled,
     format!(
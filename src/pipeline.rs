@@ -0,0 +1,120 @@
+use crate::redis_err_to_napi_err;
+use napi::bindgen_prelude::{Either4, Null};
+use napi_derive::napi;
+
+/// Accumulates commands and fires them in a single round-trip via
+/// `redis::pipe()`. Call `.cmd()` for each command, optionally `.atomic()`
+/// to wrap the batch in MULTI/EXEC, then `.execute()`. A fresh connection is
+/// opened from the originating client at execute time, matching how the
+/// rest of this crate treats connections as cheap to (re)open.
+#[napi]
+pub struct RedisPipeline {
+  client: redis::Client,
+  pipe: redis::Pipeline,
+}
+
+impl RedisPipeline {
+  pub(crate) fn new(client: redis::Client) -> Self {
+    Self {
+      client,
+      pipe: redis::pipe(),
+    }
+  }
+}
+
+/// One command's reply within a pipeline result: a nil (distinct from an
+/// empty string), a bulk string/status, an integer, or an array of bulk
+/// strings (e.g. an `LRANGE`), mirroring how `RedisClient::get` already
+/// distinguishes shapes with `Either` instead of collapsing them to text.
+/// Members of an array reply are all converted to strings, with a nil
+/// member becoming an empty string.
+pub type PipelineReply = Either4<String, i64, Vec<String>, Null>;
+
+#[napi]
+impl RedisPipeline {
+  #[napi]
+  pub fn cmd(&mut self, args: Vec<String>) {
+    self.pipe.add_command(redis::Cmd::new().arg(&args));
+  }
+
+  #[napi]
+  pub fn atomic(&mut self) {
+    self.pipe.atomic();
+  }
+
+  #[napi]
+  pub fn execute(&self) -> napi::Result<Vec<PipelineReply>> {
+    let mut connection = match self.client.get_connection() {
+      Ok(conn) => conn,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    let values: Vec<redis::Value> = match self.pipe.query(&mut connection) {
+      Ok(values) => values,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    values.into_iter().map(redis_value_to_reply).collect()
+  }
+
+  /// Non-blocking counterpart to `.execute()`, for pipelines returned by
+  /// `AsyncRedisClient::pipeline()`. Opens its own `ConnectionManager`
+  /// rather than checking one out of the caller's pool, same as `.execute()`
+  /// opening its own blocking connection.
+  #[napi]
+  pub async fn execute_async(&self) -> napi::Result<Vec<PipelineReply>> {
+    let mut manager = match self.client.get_connection_manager().await {
+      Ok(manager) => manager,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    let values: Vec<redis::Value> = match self.pipe.query_async(&mut manager).await {
+      Ok(values) => values,
+      Err(e) => return Err(redis_err_to_napi_err(&e)),
+    };
+
+    values.into_iter().map(redis_value_to_reply).collect()
+  }
+}
+
+fn redis_value_to_reply(value: redis::Value) -> napi::Result<PipelineReply> {
+  match value {
+    redis::Value::Nil => Ok(Either4::D(Null)),
+    redis::Value::Int(i) => Ok(Either4::B(i)),
+    redis::Value::Data(bytes) => Ok(Either4::A(String::from_utf8_lossy(&bytes).into_owned())),
+    redis::Value::Status(s) => Ok(Either4::A(s)),
+    redis::Value::Okay => Ok(Either4::A("OK".to_string())),
+    redis::Value::Bulk(values) => {
+      let strings = values
+        .into_iter()
+        .map(redis_value_to_bulk_member)
+        .collect::<napi::Result<Vec<String>>>()?;
+      Ok(Either4::C(strings))
+    }
+    // Catch-all for RESP3 variants (e.g. `Double`, `Boolean`, `Map`, `Set`,
+    // `Push`) that may or may not exist under this crate's pinned redis-rs
+    // version: better a clear error than a silent match-arm drift.
+    _ => Err(napi::Error::new(
+      napi::Status::Unknown,
+      "unsupported pipeline reply type".to_string(),
+    )),
+  }
+}
+
+fn redis_value_to_bulk_member(value: redis::Value) -> napi::Result<String> {
+  match value {
+    redis::Value::Nil => Ok(String::new()),
+    redis::Value::Int(i) => Ok(i.to_string()),
+    redis::Value::Data(bytes) => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+    redis::Value::Status(s) => Ok(s),
+    redis::Value::Okay => Ok("OK".to_string()),
+    redis::Value::Bulk(_) => Err(napi::Error::new(
+      napi::Status::Unknown,
+      "pipeline results do not support nested arrays".to_string(),
+    )),
+    _ => Err(napi::Error::new(
+      napi::Status::Unknown,
+      "unsupported pipeline reply type".to_string(),
+    )),
+  }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use std::time::Duration;
+
+/// `bb8::ManageConnection` impl that hands out `ConnectionManager`s.
+/// A `ConnectionManager` is a handle around an auto-reconnecting
+/// multiplexed connection, so "managing" a connection here really just
+/// means opening one manager per pool slot and checking it's still
+/// answering before handing it back out.
+pub struct RedisConnectionManager {
+  client: redis::Client,
+  connect_timeout_ms: Option<u32>,
+  response_timeout_ms: Option<u32>,
+}
+
+impl RedisConnectionManager {
+  pub fn new(client: redis::Client) -> Self {
+    Self {
+      client,
+      connect_timeout_ms: None,
+      response_timeout_ms: None,
+    }
+  }
+
+  pub fn with_timeouts(
+    client: redis::Client,
+    connect_timeout_ms: Option<u32>,
+    response_timeout_ms: Option<u32>,
+  ) -> Self {
+    Self {
+      client,
+      connect_timeout_ms,
+      response_timeout_ms,
+    }
+  }
+}
+
+#[async_trait]
+impl bb8::ManageConnection for RedisConnectionManager {
+  type Connection = ConnectionManager;
+  type Error = redis::RedisError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    if self.connect_timeout_ms.is_none() && self.response_timeout_ms.is_none() {
+      return self.client.get_connection_manager().await;
+    }
+
+    let mut config = ConnectionManagerConfig::new();
+    if let Some(ms) = self.connect_timeout_ms {
+      config = config.set_connection_timeout(Duration::from_millis(ms as u64));
+    }
+    if let Some(ms) = self.response_timeout_ms {
+      config = config.set_response_timeout(Duration::from_millis(ms as u64));
+    }
+
+    self.client.get_connection_manager_with_config(config).await
+  }
+
+  async fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+    redis::cmd("PING").query_async::<String>(conn).await?;
+    Ok(())
+  }
+
+  fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+    false
+  }
+}
+
+pub(crate) fn pool_err_to_napi_err(err: bb8::RunError<redis::RedisError>) -> napi::Error {
+  match err {
+    bb8::RunError::User(e) => crate::redis_err_to_napi_err(&e),
+    bb8::RunError::TimedOut => napi::Error::new(
+      napi::Status::Cancelled,
+      "Redis Error: timed out waiting for a pooled connection".to_string(),
+    ),
+  }
+}
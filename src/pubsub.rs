@@ -0,0 +1,275 @@
+use crate::redis_err_to_napi_err;
+use futures_util::StreamExt;
+use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi_derive::napi;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+const POLL_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// A single frame delivered to a subscriber's callback. `kind` distinguishes
+/// data frames ("message"/"pmessage") from control frames, mirroring how
+/// redis-rs's async API surfaces push messages by kind rather than
+/// collapsing everything into one shape. The only control frames delivered
+/// are one "subscribe"/"psubscribe" confirmation per channel or pattern,
+/// sent once the subscription is set up; no frame is delivered when the
+/// subscription is unsubscribed or closed.
+#[napi(object)]
+pub struct PubSubMessage {
+  pub kind: String,
+  pub channel: String,
+  pub pattern: Option<String>,
+  pub payload: Option<String>,
+}
+
+/// Handle returned by `subscribe`/`psubscribe`. The receive loop runs on a
+/// dedicated background thread; dropping the handle without calling
+/// `close()` leaves that thread running, so callers should always close it.
+#[napi]
+pub struct SubscriptionHandle {
+  stop: Arc<AtomicBool>,
+  thread: Option<JoinHandle<()>>,
+}
+
+#[napi]
+impl SubscriptionHandle {
+  #[napi]
+  pub fn unsubscribe(&mut self) -> napi::Result<()> {
+    self.stop.store(true, Ordering::SeqCst);
+    Ok(())
+  }
+
+  #[napi]
+  pub fn close(&mut self) -> napi::Result<()> {
+    self.stop.store(true, Ordering::SeqCst);
+    if let Some(thread) = self.thread.take() {
+      let _ = thread.join();
+    }
+    Ok(())
+  }
+}
+
+/// Handle returned by `AsyncRedisClient::subscribe`/`psubscribe`. The
+/// receive loop runs as a detached tokio task rather than an OS thread, so
+/// there is no handle to join; `close()` just asks the task to stop and
+/// lets it wind down on its own on the next poll.
+#[napi]
+pub struct AsyncSubscriptionHandle {
+  stop: Arc<AtomicBool>,
+}
+
+#[napi]
+impl AsyncSubscriptionHandle {
+  #[napi]
+  pub fn unsubscribe(&mut self) -> napi::Result<()> {
+    self.stop.store(true, Ordering::SeqCst);
+    Ok(())
+  }
+
+  #[napi]
+  pub fn close(&mut self) -> napi::Result<()> {
+    self.stop.store(true, Ordering::SeqCst);
+    Ok(())
+  }
+}
+
+enum Channels {
+  Literal(Vec<String>),
+  Pattern(Vec<String>),
+}
+
+pub(crate) fn subscribe(
+  client: &redis::Client,
+  channels: Vec<String>,
+  on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+) -> napi::Result<SubscriptionHandle> {
+  spawn(client, Channels::Literal(channels), on_message)
+}
+
+pub(crate) fn psubscribe(
+  client: &redis::Client,
+  patterns: Vec<String>,
+  on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+) -> napi::Result<SubscriptionHandle> {
+  spawn(client, Channels::Pattern(patterns), on_message)
+}
+
+fn spawn(
+  client: &redis::Client,
+  channels: Channels,
+  on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+) -> napi::Result<SubscriptionHandle> {
+  // Connect and subscribe synchronously, before ever spawning the thread or
+  // returning a handle, so a bad connection or a rejected SUBSCRIBE comes
+  // back as an `Err` from `subscribe()`/`psubscribe()` itself instead of a
+  // handle that silently does nothing (mirrors `spawn_async`).
+  let connection = client.get_connection().map_err(|e| redis_err_to_napi_err(&e))?;
+  let mut pubsub = connection.into_pubsub();
+
+  let (confirm_kind, names) = match &channels {
+    Channels::Literal(names) => ("subscribe", names),
+    Channels::Pattern(patterns) => ("psubscribe", patterns),
+  };
+
+  let subscribed = match &channels {
+    Channels::Literal(names) => pubsub.subscribe(names),
+    Channels::Pattern(patterns) => pubsub.psubscribe(patterns),
+  };
+  subscribed.map_err(|e| redis_err_to_napi_err(&e))?;
+
+  for name in names {
+    let confirmation = PubSubMessage {
+      kind: confirm_kind.to_string(),
+      channel: name.clone(),
+      pattern: None,
+      payload: None,
+    };
+    on_message.call(Ok(confirmation), ThreadsafeFunctionCallMode::Blocking);
+  }
+
+  // Poll with a short read timeout so the loop can notice `stop` between
+  // messages instead of blocking forever on a socket nobody is writing to.
+  pubsub
+    .set_read_timeout(Some(POLL_TIMEOUT))
+    .map_err(|e| redis_err_to_napi_err(&e))?;
+
+  let stop = Arc::new(AtomicBool::new(false));
+  let thread_stop = stop.clone();
+
+  let thread = std::thread::spawn(move || {
+    while !thread_stop.load(Ordering::SeqCst) {
+      let msg = match pubsub.get_message() {
+        Ok(msg) => msg,
+        // A read timeout is expected every `POLL_TIMEOUT` with no traffic;
+        // just loop back around and check `stop` again.
+        Err(e) if e.is_timeout() => continue,
+        // Anything else (connection dropped, protocol error, ...) means this
+        // subscription is dead: tell the caller and stop spinning on it.
+        Err(e) => {
+          on_message.call(
+            Err(redis_err_to_napi_err(&e)),
+            ThreadsafeFunctionCallMode::Blocking,
+          );
+          thread_stop.store(true, Ordering::SeqCst);
+          break;
+        }
+      };
+
+      let pattern: Option<String> = msg.get_pattern().ok();
+      let payload: Option<String> = msg.get_payload().ok();
+      let kind = if pattern.is_some() { "pmessage" } else { "message" };
+
+      let event = PubSubMessage {
+        kind: kind.to_string(),
+        channel: msg.get_channel_name().to_string(),
+        pattern,
+        payload,
+      };
+
+      on_message.call(Ok(event), ThreadsafeFunctionCallMode::Blocking);
+    }
+
+    let unsubscribed = match &channels {
+      Channels::Literal(names) => pubsub.unsubscribe(names),
+      Channels::Pattern(patterns) => pubsub.punsubscribe(patterns),
+    };
+    let _ = unsubscribed;
+  });
+
+  Ok(SubscriptionHandle {
+    stop,
+    thread: Some(thread),
+  })
+}
+
+pub(crate) async fn subscribe_async(
+  client: &redis::Client,
+  channels: Vec<String>,
+  on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+) -> napi::Result<AsyncSubscriptionHandle> {
+  spawn_async(client, Channels::Literal(channels), on_message).await
+}
+
+pub(crate) async fn psubscribe_async(
+  client: &redis::Client,
+  patterns: Vec<String>,
+  on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+) -> napi::Result<AsyncSubscriptionHandle> {
+  spawn_async(client, Channels::Pattern(patterns), on_message).await
+}
+
+async fn spawn_async(
+  client: &redis::Client,
+  channels: Channels,
+  on_message: ThreadsafeFunction<PubSubMessage, ErrorStrategy::CalleeHandled>,
+) -> napi::Result<AsyncSubscriptionHandle> {
+  let mut pubsub = client
+    .get_async_pubsub()
+    .await
+    .map_err(|e| redis_err_to_napi_err(&e))?;
+
+  let (confirm_kind, names) = match &channels {
+    Channels::Literal(names) => ("subscribe", names),
+    Channels::Pattern(patterns) => ("psubscribe", patterns),
+  };
+
+  let subscribed = match &channels {
+    Channels::Literal(names) => pubsub.subscribe(names).await,
+    Channels::Pattern(patterns) => pubsub.psubscribe(patterns).await,
+  };
+  subscribed.map_err(|e| redis_err_to_napi_err(&e))?;
+
+  for name in names {
+    let confirmation = PubSubMessage {
+      kind: confirm_kind.to_string(),
+      channel: name.clone(),
+      pattern: None,
+      payload: None,
+    };
+    on_message.call(Ok(confirmation), ThreadsafeFunctionCallMode::Blocking);
+  }
+
+  let stop = Arc::new(AtomicBool::new(false));
+  let task_stop = stop.clone();
+
+  napi::tokio::spawn(async move {
+    let mut stream = pubsub.on_message();
+
+    while !task_stop.load(Ordering::SeqCst) {
+      // Poll with a timeout so the task can notice `stop` even when the
+      // channel is otherwise silent, same reasoning as the sync loop.
+      let msg = match tokio::time::timeout(POLL_TIMEOUT, stream.next()).await {
+        Ok(Some(msg)) => msg,
+        // The stream ended, meaning the connection is gone: tell the caller
+        // and stop, rather than spinning on an exhausted stream.
+        Ok(None) => {
+          let err = napi::Error::new(
+            napi::Status::Cancelled,
+            "Redis Error: pubsub connection closed".to_string(),
+          );
+          on_message.call(Err(err), ThreadsafeFunctionCallMode::Blocking);
+          break;
+        }
+        Err(_) => continue,
+      };
+
+      let pattern: Option<String> = msg.get_pattern().ok();
+      let payload: Option<String> = msg.get_payload().ok();
+      let kind = if pattern.is_some() { "pmessage" } else { "message" };
+
+      let event = PubSubMessage {
+        kind: kind.to_string(),
+        channel: msg.get_channel_name().to_string(),
+        pattern,
+        payload,
+      };
+
+      on_message.call(Ok(event), ThreadsafeFunctionCallMode::Blocking);
+    }
+  });
+
+  Ok(AsyncSubscriptionHandle { stop })
+}
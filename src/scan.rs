@@ -0,0 +1,122 @@
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi_derive::napi;
+
+/// One step of a cursor-based scan. Iteration is complete once `cursor` is
+/// `"0"`; the same key may legitimately reappear across steps, so callers
+/// that need a deduplicated view should dedupe on their side.
+#[napi(object)]
+pub struct ScanResult {
+  pub cursor: String,
+  pub keys: Vec<String>,
+}
+
+pub(crate) fn scan_step(
+  connection: &mut redis::Connection,
+  command: &str,
+  key: Option<&str>,
+  cursor: &str,
+  pattern: Option<&str>,
+  count: Option<u32>,
+) -> redis::RedisResult<ScanResult> {
+  let mut cmd = redis::cmd(command);
+  if let Some(key) = key {
+    cmd.arg(key);
+  }
+  cmd.arg(cursor);
+  if let Some(pattern) = pattern {
+    cmd.arg("MATCH").arg(pattern);
+  }
+  if let Some(count) = count {
+    cmd.arg("COUNT").arg(count);
+  }
+
+  let (cursor, keys): (String, Vec<String>) = cmd.query(connection)?;
+  Ok(ScanResult { cursor, keys })
+}
+
+pub(crate) fn scan_all(
+  connection: &mut redis::Connection,
+  command: &str,
+  key: Option<&str>,
+  pattern: Option<String>,
+  count: Option<u32>,
+  on_batch: ThreadsafeFunction<Vec<String>, ErrorStrategy::CalleeHandled>,
+) -> redis::RedisResult<()> {
+  let mut cursor = "0".to_string();
+  loop {
+    let step = scan_step(
+      connection,
+      command,
+      key,
+      &cursor,
+      pattern.as_deref(),
+      count,
+    )?;
+
+    if !step.keys.is_empty() {
+      on_batch.call(Ok(step.keys), ThreadsafeFunctionCallMode::Blocking);
+    }
+
+    cursor = step.cursor;
+    if cursor == "0" {
+      return Ok(());
+    }
+  }
+}
+
+// Non-blocking counterparts, for `AsyncRedisClient`'s pooled connections.
+
+pub(crate) async fn scan_step_async(
+  connection: &mut redis::aio::ConnectionManager,
+  command: &str,
+  key: Option<&str>,
+  cursor: &str,
+  pattern: Option<&str>,
+  count: Option<u32>,
+) -> redis::RedisResult<ScanResult> {
+  let mut cmd = redis::cmd(command);
+  if let Some(key) = key {
+    cmd.arg(key);
+  }
+  cmd.arg(cursor);
+  if let Some(pattern) = pattern {
+    cmd.arg("MATCH").arg(pattern);
+  }
+  if let Some(count) = count {
+    cmd.arg("COUNT").arg(count);
+  }
+
+  let (cursor, keys): (String, Vec<String>) = cmd.query_async(connection).await?;
+  Ok(ScanResult { cursor, keys })
+}
+
+pub(crate) async fn scan_all_async(
+  connection: &mut redis::aio::ConnectionManager,
+  command: &str,
+  key: Option<&str>,
+  pattern: Option<String>,
+  count: Option<u32>,
+  on_batch: ThreadsafeFunction<Vec<String>, ErrorStrategy::CalleeHandled>,
+) -> redis::RedisResult<()> {
+  let mut cursor = "0".to_string();
+  loop {
+    let step = scan_step_async(
+      connection,
+      command,
+      key,
+      &cursor,
+      pattern.as_deref(),
+      count,
+    )
+    .await?;
+
+    if !step.keys.is_empty() {
+      on_batch.call(Ok(step.keys), ThreadsafeFunctionCallMode::Blocking);
+    }
+
+    cursor = step.cursor;
+    if cursor == "0" {
+      return Ok(());
+    }
+  }
+}